@@ -0,0 +1,267 @@
+use std::io::{self, Read, Write};
+
+/// Describes when a [`FaultyReader`] or [`FaultyWriter`] should
+/// inject an [`io::Error`] instead of delegating to the inner
+/// stream.
+enum FaultSchedule {
+    /// Fault on these specific (1-indexed) call numbers.
+    Calls(Vec<usize>),
+    /// Fault once, on the first call made once `after` total bytes
+    /// have been read/written.
+    AfterBytes { after: usize, triggered: bool },
+    /// Fault on every `n`th call.
+    EveryNthCall(usize),
+}
+
+impl FaultSchedule {
+    fn is_due(&mut self, call: usize, bytes: usize) -> bool {
+        match self {
+            FaultSchedule::Calls(calls) => calls.contains(&call),
+            FaultSchedule::AfterBytes { after, triggered } => {
+                if !*triggered && bytes >= *after {
+                    *triggered = true;
+                    true
+                } else {
+                    false
+                }
+            }
+            FaultSchedule::EveryNthCall(n) => call.is_multiple_of(*n),
+        }
+    }
+}
+
+/// Wraps a reader `R` and injects [`io::Error`]s on a configurable
+/// schedule, so that `Interrupted`-retry loops and other
+/// mid-stream error handling can be exercised. Faults do not
+/// consume bytes from the inner reader; between faults, calls are
+/// delegated to it unchanged.
+///
+/// # Example
+/// ```
+/// use std::io::{ErrorKind, Read};
+/// use test_utils::streams::FaultyReader;
+///
+/// let mut reader = FaultyReader::on_calls(&b"hello"[..], [1], ErrorKind::Interrupted);
+/// let mut buf = [0u8; 5];
+/// assert_eq!(reader.read(&mut buf).unwrap_err().kind(), ErrorKind::Interrupted);
+/// assert_eq!(reader.read(&mut buf).unwrap(), 5);
+/// assert_eq!(reader.faults(), 1);
+/// ```
+pub struct FaultyReader<R> {
+    inner: R,
+    schedule: FaultSchedule,
+    kind: io::ErrorKind,
+    calls: usize,
+    bytes: usize,
+    faults: usize,
+}
+
+impl<R> FaultyReader<R> {
+    /// Creates a [`FaultyReader`] that faults with `kind` on the
+    /// given (1-indexed) call numbers, e.g. `[2, 5]`.
+    pub fn on_calls(inner: R, calls: impl IntoIterator<Item = usize>, kind: io::ErrorKind) -> Self {
+        Self::with_schedule(inner, FaultSchedule::Calls(calls.into_iter().collect()), kind)
+    }
+
+    /// Creates a [`FaultyReader`] that faults once with `kind`, on
+    /// the first call made once `after` total bytes have been read.
+    pub fn after_bytes(inner: R, after: usize, kind: io::ErrorKind) -> Self {
+        Self::with_schedule(
+            inner,
+            FaultSchedule::AfterBytes {
+                after,
+                triggered: false,
+            },
+            kind,
+        )
+    }
+
+    /// Creates a [`FaultyReader`] that faults with `kind` on every
+    /// `n`th call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    pub fn every_nth_call(inner: R, n: usize, kind: io::ErrorKind) -> Self {
+        assert!(n > 0, "every_nth_call: n must be greater than 0");
+        Self::with_schedule(inner, FaultSchedule::EveryNthCall(n), kind)
+    }
+
+    fn with_schedule(inner: R, schedule: FaultSchedule, kind: io::ErrorKind) -> Self {
+        Self {
+            inner,
+            schedule,
+            kind,
+            calls: 0,
+            bytes: 0,
+            faults: 0,
+        }
+    }
+
+    /// Returns the number of faults injected so far.
+    pub fn faults(&self) -> usize {
+        self.faults
+    }
+}
+
+impl<R> Read for FaultyReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.calls += 1;
+
+        if self.schedule.is_due(self.calls, self.bytes) {
+            self.faults += 1;
+            return Err(io::Error::from(self.kind));
+        }
+
+        let n = self.inner.read(buf)?;
+        self.bytes += n;
+        Ok(n)
+    }
+}
+
+/// Wraps a writer `W` and injects [`io::Error`]s on a configurable
+/// schedule, so that `Interrupted`-retry loops and other
+/// mid-stream error handling can be exercised. Faults do not write
+/// any bytes to the inner writer; between faults, calls are
+/// delegated to it unchanged.
+///
+/// # Example
+/// ```
+/// use std::io::{ErrorKind, Write};
+/// use test_utils::streams::{FaultyWriter, VoidWriter};
+///
+/// let mut writer = FaultyWriter::every_nth_call(VoidWriter::new(), 2, ErrorKind::Interrupted);
+/// assert_eq!(writer.write(b"hi").unwrap(), 2);
+/// assert_eq!(writer.write(b"hi").unwrap_err().kind(), ErrorKind::Interrupted);
+/// assert_eq!(writer.faults(), 1);
+/// ```
+pub struct FaultyWriter<W> {
+    inner: W,
+    schedule: FaultSchedule,
+    kind: io::ErrorKind,
+    calls: usize,
+    bytes: usize,
+    faults: usize,
+}
+
+impl<W> FaultyWriter<W> {
+    /// Creates a [`FaultyWriter`] that faults with `kind` on the
+    /// given (1-indexed) call numbers, e.g. `[2, 5]`.
+    pub fn on_calls(inner: W, calls: impl IntoIterator<Item = usize>, kind: io::ErrorKind) -> Self {
+        Self::with_schedule(inner, FaultSchedule::Calls(calls.into_iter().collect()), kind)
+    }
+
+    /// Creates a [`FaultyWriter`] that faults once with `kind`, on
+    /// the first call made once `after` total bytes have been
+    /// written.
+    pub fn after_bytes(inner: W, after: usize, kind: io::ErrorKind) -> Self {
+        Self::with_schedule(
+            inner,
+            FaultSchedule::AfterBytes {
+                after,
+                triggered: false,
+            },
+            kind,
+        )
+    }
+
+    /// Creates a [`FaultyWriter`] that faults with `kind` on every
+    /// `n`th call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    pub fn every_nth_call(inner: W, n: usize, kind: io::ErrorKind) -> Self {
+        assert!(n > 0, "every_nth_call: n must be greater than 0");
+        Self::with_schedule(inner, FaultSchedule::EveryNthCall(n), kind)
+    }
+
+    fn with_schedule(inner: W, schedule: FaultSchedule, kind: io::ErrorKind) -> Self {
+        Self {
+            inner,
+            schedule,
+            kind,
+            calls: 0,
+            bytes: 0,
+            faults: 0,
+        }
+    }
+
+    /// Returns the number of faults injected so far.
+    pub fn faults(&self) -> usize {
+        self.faults
+    }
+}
+
+impl<W> Write for FaultyWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.calls += 1;
+
+        if self.schedule.is_due(self.calls, self.bytes) {
+            self.faults += 1;
+            return Err(io::Error::from(self.kind));
+        }
+
+        let n = self.inner.write(buf)?;
+        self.bytes += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::VoidWriter;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn read_on_calls() {
+        let mut r = FaultyReader::on_calls(&b"hello world"[..], [2, 5], ErrorKind::Interrupted);
+        let mut buf = [0u8; 4];
+
+        assert_eq!(r.read(&mut buf).unwrap(), 4);
+        assert_eq!(r.read(&mut buf).unwrap_err().kind(), ErrorKind::Interrupted);
+        assert_eq!(r.read(&mut buf).unwrap(), 4);
+        assert_eq!(r.read(&mut buf).unwrap(), 3);
+        assert_eq!(r.read(&mut buf).unwrap_err().kind(), ErrorKind::Interrupted);
+        assert_eq!(r.faults(), 2);
+    }
+
+    #[test]
+    fn read_after_bytes() {
+        let mut r = FaultyReader::after_bytes(&b"hello world"[..], 4, ErrorKind::TimedOut);
+        let mut buf = [0u8; 4];
+
+        assert_eq!(r.read(&mut buf).unwrap(), 4);
+        assert_eq!(r.read(&mut buf).unwrap_err().kind(), ErrorKind::TimedOut);
+        assert_eq!(r.read(&mut buf).unwrap(), 4);
+        assert_eq!(r.faults(), 1);
+    }
+
+    #[test]
+    fn write_every_nth_call() {
+        let mut w = FaultyWriter::every_nth_call(VoidWriter::new(), 3, ErrorKind::Interrupted);
+
+        assert_eq!(w.write(b"a").unwrap(), 1);
+        assert_eq!(w.write(b"b").unwrap(), 1);
+        assert_eq!(w.write(b"c").unwrap_err().kind(), ErrorKind::Interrupted);
+        assert_eq!(w.write(b"d").unwrap(), 1);
+        assert_eq!(w.faults(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "every_nth_call: n must be greater than 0")]
+    fn every_nth_call_rejects_zero() {
+        FaultyReader::every_nth_call(&b"hello"[..], 0, ErrorKind::Interrupted);
+    }
+}
@@ -0,0 +1,147 @@
+use std::io::{self, Write};
+
+use super::VoidWriter;
+
+/// How many bytes a [`PartialWriter`] accepts on its next `write`
+/// call.
+enum AcceptPattern {
+    /// Always accept at most this many bytes.
+    Max(usize),
+    /// Accept at most this many bytes, cycling through the list on
+    /// every call.
+    Cycle(Vec<usize>),
+}
+
+/// Wraps a writer `W` and accepts at most a limited number of bytes
+/// per `write` call, forcing callers to loop, exactly as a real
+/// `Write` implementation may. Counts written bytes and calls to
+/// `write` like [`VoidWriter`].
+///
+/// # Example
+/// ```
+/// use std::io::{copy, Cursor};
+/// use test_utils::streams::PartialWriter;
+///
+/// let mut reader = Cursor::new(vec![0u8; 10]);
+/// let mut writer = PartialWriter::void(4);
+/// let read = copy(&mut reader, &mut writer).unwrap();
+/// assert_eq!(read, 10);
+/// assert_eq!(writer.wrote(), 10);
+/// assert_eq!(writer.calls(), 3);
+/// ```
+pub struct PartialWriter<W> {
+    inner: W,
+    pattern: AcceptPattern,
+    wrote: usize,
+    calls: usize,
+}
+
+impl<W> PartialWriter<W> {
+    /// Creates a [`PartialWriter`] that accepts at most `max` bytes
+    /// per `write` call.
+    pub fn new(inner: W, max: usize) -> Self {
+        Self::with_pattern(inner, AcceptPattern::Max(max))
+    }
+
+    /// Creates a [`PartialWriter`] that cycles through `sizes`,
+    /// accepting at most that many bytes on each successive
+    /// `write` call, to reproduce pathological backpressure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sizes` is empty.
+    pub fn cycling(inner: W, sizes: impl IntoIterator<Item = usize>) -> Self {
+        let sizes: Vec<usize> = sizes.into_iter().collect();
+        assert!(!sizes.is_empty(), "cycling: sizes must not be empty");
+        Self::with_pattern(inner, AcceptPattern::Cycle(sizes))
+    }
+
+    fn with_pattern(inner: W, pattern: AcceptPattern) -> Self {
+        Self {
+            inner,
+            pattern,
+            wrote: 0,
+            calls: 0,
+        }
+    }
+
+    /// Returns the amount of written bytes to the [`PartialWriter`].
+    pub fn wrote(&self) -> usize {
+        self.wrote
+    }
+
+    /// Returns the amount of calls to `write` to the
+    /// [`PartialWriter`].
+    pub fn calls(&self) -> usize {
+        self.calls
+    }
+
+    fn accept(&mut self, len: usize) -> usize {
+        let max = match &self.pattern {
+            AcceptPattern::Max(max) => *max,
+            AcceptPattern::Cycle(sizes) => sizes[(self.calls - 1) % sizes.len()],
+        };
+        max.min(len)
+    }
+}
+
+impl PartialWriter<VoidWriter> {
+    /// Creates a [`PartialWriter`] wrapping a [`VoidWriter`] that
+    /// accepts at most `max` bytes per `write` call.
+    pub fn void(max: usize) -> Self {
+        Self::new(VoidWriter::new(), max)
+    }
+}
+
+impl<W> Write for PartialWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.calls += 1;
+        let accept = self.accept(buf.len());
+
+        self.inner.write_all(&buf[..accept])?;
+        self.wrote += accept;
+
+        Ok(accept)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_max() {
+        let mut w = PartialWriter::void(4);
+
+        assert_eq!(w.write(b"hello world").unwrap(), 4);
+        assert_eq!(w.write(b"o world").unwrap(), 4);
+        assert_eq!(w.write(b"rld").unwrap(), 3);
+        assert_eq!(w.wrote(), 11);
+        assert_eq!(w.calls(), 3);
+    }
+
+    #[test]
+    fn write_cycling() {
+        let mut w = PartialWriter::cycling(VoidWriter::new(), [1, 0, 4096]);
+
+        assert_eq!(w.write(b"hello").unwrap(), 1);
+        assert_eq!(w.write(b"ello").unwrap(), 0);
+        assert_eq!(w.write(b"ello").unwrap(), 4);
+        assert_eq!(w.write(b"world").unwrap(), 1);
+        assert_eq!(w.wrote(), 6);
+        assert_eq!(w.calls(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "cycling: sizes must not be empty")]
+    fn cycling_rejects_empty_sizes() {
+        PartialWriter::cycling(VoidWriter::new(), []);
+    }
+}
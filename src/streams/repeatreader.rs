@@ -1,4 +1,4 @@
-use std::io::{Cursor, Read, Result, Seek, SeekFrom};
+use std::io::{BufRead, Cursor, IoSliceMut, Read, Result, Seek, SeekFrom};
 
 /// Can be created with a given size and then repeats the
 /// given content until finished reading.
@@ -101,6 +101,90 @@ where
 
         Ok(ln)
     }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize> {
+        let mut total = 0;
+
+        for buf in bufs.iter_mut() {
+            if self.left() == 0 {
+                break;
+            }
+            total += self.read(buf)?;
+        }
+
+        Ok(total)
+    }
+}
+
+impl<S> Seek for RepeatReader<S>
+where
+    S: Seek,
+{
+    /// Seeks within the *virtual* repeated stream, not the
+    /// underlying `contents`. The new position is clamped into
+    /// `0..=size` and the inner cursor is wrapped around the
+    /// content length accordingly.
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let size = self.size as i64;
+        let current = self.read as i64;
+
+        let new_offset = match pos {
+            SeekFrom::Start(n) => n as i64,
+            SeekFrom::Current(n) => current + n,
+            SeekFrom::End(n) => size + n,
+        }
+        .clamp(0, size) as usize;
+
+        let content_len = self.contents.seek(SeekFrom::End(0))? as usize;
+        if content_len == 0 {
+            self.contents.seek(SeekFrom::Start(0))?;
+        } else {
+            self.contents
+                .seek(SeekFrom::Start((new_offset % content_len) as u64))?;
+        }
+
+        self.read = new_offset;
+
+        Ok(new_offset as u64)
+    }
+}
+
+impl<S> BufRead for RepeatReader<S>
+where
+    S: BufRead + Seek,
+{
+    /// Fills the buffer from the repeated content, wrapping back
+    /// to offset `0` when its end is hit. Returns an empty slice
+    /// exactly when `left() == 0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `contents` is empty while `left() > 0`, since no
+    /// bytes can then be produced without violating that invariant.
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        if self.left() == 0 {
+            return Ok(&[]);
+        }
+
+        if self.contents.fill_buf()?.is_empty() {
+            self.contents.seek(SeekFrom::Start(0))?;
+        }
+
+        let left = self.left();
+        let buf = self.contents.fill_buf()?;
+        assert!(
+            !buf.is_empty(),
+            "RepeatReader: contents must not be empty while left() > 0"
+        );
+        let ln = buf.len().min(left);
+
+        Ok(&buf[..ln])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.contents.consume(amt);
+        self.read += amt;
+    }
 }
 
 #[cfg(test)]
@@ -134,4 +218,79 @@ mod tests {
         rr.read_to_string(&mut res).unwrap();
         assert_eq!(res, "");
     }
+
+    #[test]
+    fn read_vectored() {
+        let mut rr = RepeatReader::from_str(15, "hello world");
+        let mut a = [0u8; 5];
+        let mut b = [0u8; 5];
+        let mut c = [0u8; 5];
+        let read = rr
+            .read_vectored(&mut [
+                IoSliceMut::new(&mut a),
+                IoSliceMut::new(&mut b),
+                IoSliceMut::new(&mut c),
+            ])
+            .unwrap();
+        assert_eq!(read, 15);
+        assert_eq!(&a, b"hello");
+        assert_eq!(&b, b" worl");
+        assert_eq!(&c, b"dhell");
+        assert_eq!(rr.left(), 0);
+    }
+
+    #[test]
+    fn fill_buf() {
+        let mut rr = RepeatReader::from_str(15, "hello world");
+
+        assert_eq!(rr.fill_buf().unwrap(), b"hello world");
+        rr.consume(5);
+        assert_eq!(rr.left(), 10);
+
+        assert_eq!(rr.fill_buf().unwrap(), b" world");
+        rr.consume(6);
+
+        assert_eq!(rr.fill_buf().unwrap(), b"hell");
+        rr.consume(4);
+        assert_eq!(rr.left(), 0);
+
+        assert_eq!(rr.fill_buf().unwrap(), b"");
+    }
+
+    #[test]
+    #[should_panic(expected = "contents must not be empty")]
+    fn fill_buf_panics_on_empty_contents() {
+        let mut rr = RepeatReader::from_str(10, "");
+        let _ = rr.fill_buf();
+    }
+
+    #[test]
+    fn seek() {
+        let mut rr = RepeatReader::from_str(26, "hello world");
+
+        assert_eq!(rr.seek(SeekFrom::Start(11)).unwrap(), 11);
+        let mut res = String::new();
+        rr.read_to_string(&mut res).unwrap();
+        assert_eq!(res, "hello worldhell");
+
+        rr.seek(SeekFrom::Start(0)).unwrap();
+        assert_eq!(rr.seek(SeekFrom::Current(5)).unwrap(), 5);
+        assert_eq!(rr.left(), 21);
+
+        assert_eq!(rr.seek(SeekFrom::End(-1)).unwrap(), 25);
+        assert_eq!(rr.left(), 1);
+
+        // clamps into 0..=size
+        assert_eq!(rr.seek(SeekFrom::Start(1000)).unwrap(), 26);
+        assert_eq!(rr.left(), 0);
+        assert_eq!(rr.seek(SeekFrom::Current(-1000)).unwrap(), 0);
+        assert_eq!(rr.left(), 26);
+    }
+
+    #[test]
+    fn lines() {
+        let rr = RepeatReader::from_str(8, "ab\n");
+        let lines: Vec<_> = rr.lines().map(|l| l.unwrap()).collect();
+        assert_eq!(lines, vec!["ab", "ab", "ab"]);
+    }
 }
@@ -1,5 +1,5 @@
 use core::fmt;
-use std::io;
+use std::io::{self, IoSlice};
 
 /// Writes everything successfully to the endless void of nothingness ...
 /// and counts written bytes and calls to `write`!
@@ -50,6 +50,13 @@ impl io::Write for VoidWriter {
     fn flush(&mut self) -> io::Result<()> {
         Ok(())
     }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let total: usize = bufs.iter().map(|buf| buf.len()).sum();
+        self.wrote += total;
+        self.calls += 1;
+        Ok(total)
+    }
 }
 
 impl fmt::Display for VoidWriter {